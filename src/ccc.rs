@@ -0,0 +1,63 @@
+// Copyright 2012-2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Canonical decomposition that also yields each scalar's combining class.
+//!
+//! Downstream consumers such as collators and grapheme segmenters fuse an
+//! NFD pass with their own logic and already need the canonical combining
+//! class of every decomposed scalar. [`new_canonical_with_ccc`] wraps the
+//! crate's [`Decompositions`] iterator so the canonical decomposition and
+//! canonical reordering are performed by exactly the same code as `nfd`, and
+//! pairs each produced scalar with its canonical combining class.
+//!
+//! This lives in its own `pub mod ccc` rather than under `decompose`, since
+//! `Decompositions`' fields are private and the adapter only needs its public
+//! iterator surface; exposing it here keeps that boundary explicit.
+
+use crate::lookups::canonical_combining_class;
+use crate::Decompositions;
+use crate::UnicodeNormalization;
+
+/// Returns an iterator over `chars` in Unicode Normalization Form D, pairing
+/// each decomposed scalar with its canonical combining class (`0` for
+/// starters).
+#[inline]
+pub fn new_canonical_with_ccc<I: Iterator<Item = char>>(chars: I) -> DecompositionsWithCcc<I> {
+    DecompositionsWithCcc {
+        inner: chars.nfd(),
+    }
+}
+
+/// External iterator over `(char, canonical_combining_class)` pairs of a
+/// canonical decomposition, in canonical order.
+///
+/// Created with [`new_canonical_with_ccc`].
+#[derive(Clone)]
+pub struct DecompositionsWithCcc<I> {
+    inner: Decompositions<I>,
+}
+
+impl<I: Iterator<Item = char>> Iterator for DecompositionsWithCcc<I> {
+    type Item = (char, u8);
+
+    #[inline]
+    fn next(&mut self) -> Option<(char, u8)> {
+        // `Decompositions` has already applied canonical ordering, so the
+        // combining class of each yielded scalar is its plain lookup value.
+        self.inner
+            .next()
+            .map(|ch| (ch, canonical_combining_class(ch)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
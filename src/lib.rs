@@ -63,10 +63,17 @@ pub use crate::stream_safe::StreamSafe;
 pub use crate::tables::UNICODE_VERSION;
 use core::str::Chars;
 
+#[cfg(not(feature = "std"))]
+use alloc::{borrow::Cow, string::String};
+#[cfg(feature = "std")]
+use std::{borrow::Cow, string::String};
+
 mod no_std_prelude;
 
+pub mod ccc;
 mod decompose;
 mod lookups;
+pub mod lossy;
 mod normalize;
 mod perfect_hash;
 mod quick_check;
@@ -199,6 +206,121 @@ impl<'a> UnicodeNormalization<Chars<'a>> for &'a str {
     }
 }
 
+/// Methods for normalizing a string slice into an owned form only when it is
+/// not already in the requested normalization form.
+///
+/// Each method first runs the corresponding quick-check. When the slice is
+/// definitively already normalized it is returned as `Cow::Borrowed` with no
+/// allocation; otherwise the confirmed-normalized prefix is kept verbatim and
+/// only the remainder is run through the normalization pipeline.
+pub trait UnicodeNormalizationCow {
+    /// Returns the string in Unicode Normalization Form C, borrowing the input
+    /// unchanged when it is already in NFC.
+    fn nfc_cow(&self) -> Cow<'_, str>;
+
+    /// Returns the string in Unicode Normalization Form D, borrowing the input
+    /// unchanged when it is already in NFD.
+    fn nfd_cow(&self) -> Cow<'_, str>;
+
+    /// Returns the string in Unicode Normalization Form KC, borrowing the input
+    /// unchanged when it is already in NFKC.
+    fn nfkc_cow(&self) -> Cow<'_, str>;
+
+    /// Returns the string in Unicode Normalization Form KD, borrowing the input
+    /// unchanged when it is already in NFKD.
+    fn nfkd_cow(&self) -> Cow<'_, str>;
+}
+
+impl UnicodeNormalizationCow for str {
+    #[inline]
+    fn nfc_cow(&self) -> Cow<'_, str> {
+        match quick_check::is_nfc_quick(self.chars()) {
+            IsNormalized::Yes => Cow::Borrowed(self),
+            _ => {
+                let split =
+                    borrowable_prefix(self, |c| is_nfc_quick(core::iter::once(c)) == IsNormalized::Yes);
+                let mut buf = String::from(&self[..split]);
+                buf.extend(self[split..].nfc());
+                Cow::Owned(buf)
+            }
+        }
+    }
+
+    #[inline]
+    fn nfd_cow(&self) -> Cow<'_, str> {
+        match quick_check::is_nfd_quick(self.chars()) {
+            IsNormalized::Yes => Cow::Borrowed(self),
+            _ => {
+                let split =
+                    borrowable_prefix(self, |c| is_nfd_quick(core::iter::once(c)) == IsNormalized::Yes);
+                let mut buf = String::from(&self[..split]);
+                buf.extend(self[split..].nfd());
+                Cow::Owned(buf)
+            }
+        }
+    }
+
+    #[inline]
+    fn nfkc_cow(&self) -> Cow<'_, str> {
+        match quick_check::is_nfkc_quick(self.chars()) {
+            IsNormalized::Yes => Cow::Borrowed(self),
+            _ => {
+                let split =
+                    borrowable_prefix(self, |c| is_nfkc_quick(core::iter::once(c)) == IsNormalized::Yes);
+                let mut buf = String::from(&self[..split]);
+                buf.extend(self[split..].nfkc());
+                Cow::Owned(buf)
+            }
+        }
+    }
+
+    #[inline]
+    fn nfkd_cow(&self) -> Cow<'_, str> {
+        match quick_check::is_nfkd_quick(self.chars()) {
+            IsNormalized::Yes => Cow::Borrowed(self),
+            _ => {
+                let split =
+                    borrowable_prefix(self, |c| is_nfkd_quick(core::iter::once(c)) == IsNormalized::Yes);
+                let mut buf = String::from(&self[..split]);
+                buf.extend(self[split..].nfkd());
+                Cow::Owned(buf)
+            }
+        }
+    }
+}
+
+/// Returns the byte length of the leading run of `s` that can be copied
+/// verbatim because it is already in the target normalization form, where
+/// `is_allowed` reports whether a scalar is left untouched by that form (the
+/// per-character quick-check).
+///
+/// The run is extended across every *inert starter* — a starter (ccc 0) that
+/// `is_allowed` confirms and that is not a Hangul jamo/syllable (whose
+/// composition is algorithmic). Nothing before such a scalar can reorder or
+/// compose across it, so the prefix up to the most recent inert starter is
+/// already normalized. That final inert starter is itself excluded, since the
+/// first affected scalar may compose with or reorder around it.
+fn borrowable_prefix(s: &str, is_allowed: impl Fn(char) -> bool) -> usize {
+    let mut boundary = 0;
+    for (i, c) in s.char_indices() {
+        if lookups::canonical_combining_class(c) == 0 && !is_hangul(c) && is_allowed(c) {
+            boundary = i;
+        } else {
+            break;
+        }
+    }
+    boundary
+}
+
+/// Whether `c` is a Hangul syllable or conjoining jamo, whose composition and
+/// decomposition are handled algorithmically rather than through the tables.
+fn is_hangul(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x1100..=0x11FF | 0xA960..=0xA97F | 0xAC00..=0xD7A3 | 0xD7B0..=0xD7FF
+    )
+}
+
 impl<I: Iterator<Item = char>> UnicodeNormalization<I> for I {
     #[inline]
     fn nfd(self) -> Decompositions<I> {
@@ -0,0 +1,170 @@
+// Copyright 2012-2015 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Entry points that normalize potentially-invalid byte inputs.
+//!
+//! Following the `icu_normalizer` design, decoding possibly ill-formed
+//! UTF-8 or UTF-16 into `char`s is kept out of the normalizer internals:
+//! these adapters lazily decode their input, substituting `U+FFFD` for
+//! each maximal ill-formed subsequence, and yield `char`s that can be fed
+//! straight into the normalization pipeline.
+//!
+//! Because [`UnicodeNormalization`](crate::UnicodeNormalization) is
+//! implemented for every `Iterator<Item = char>`, the returned iterators
+//! compose directly with `nfc`/`nfd`/`nfkc`/`nfkd`:
+//!
+//! ```rust
+//! use unicode_normalization::lossy;
+//! use unicode_normalization::UnicodeNormalization;
+//!
+//! let bytes = b"A\xCC\x8A";
+//! let s: String = lossy::from_utf8(bytes).nfc().collect();
+//! assert_eq!(s, "\u{c5}");
+//! ```
+
+use core::char;
+
+const REPLACEMENT_CHARACTER: char = '\u{FFFD}';
+
+/// Lazily decodes potentially-invalid UTF-8, substituting `U+FFFD` for each
+/// maximal ill-formed subsequence as defined by the Unicode standard.
+///
+/// The yielded `char`s can be fed into any normalization form, e.g.
+/// `lossy::from_utf8(bytes).nfd()`.
+#[inline]
+pub fn from_utf8(bytes: &[u8]) -> Utf8Chars<'_> {
+    Utf8Chars { bytes }
+}
+
+/// Alias for [`from_utf8`], named to mirror the lossy-decode terminology
+/// used by callers that hold raw byte buffers.
+#[inline]
+pub fn from_utf8_lossy_iter(bytes: &[u8]) -> Utf8Chars<'_> {
+    from_utf8(bytes)
+}
+
+/// Lazily decodes potentially-invalid UTF-16, substituting `U+FFFD` for each
+/// unpaired surrogate.
+///
+/// The yielded `char`s can be fed into any normalization form, e.g.
+/// `lossy::from_utf16(units).nfc()`.
+#[inline]
+pub fn from_utf16(units: &[u16]) -> Utf16Chars<'_> {
+    Utf16Chars { units }
+}
+
+/// Iterator over the scalar values of a potentially-invalid UTF-8 slice,
+/// emitting `U+FFFD` for each maximal ill-formed subsequence.
+///
+/// Created with [`from_utf8`].
+#[derive(Clone, Debug)]
+pub struct Utf8Chars<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Iterator for Utf8Chars<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let &first = self.bytes.first()?;
+        // The leading byte fixes how many bytes a well-formed scalar would
+        // occupy, so we only ever validate that small window (1..=4 bytes)
+        // and never rescan the tail — each call is O(1).
+        let width = utf8_width(first);
+        let take = if width == 0 {
+            1
+        } else {
+            width.min(self.bytes.len())
+        };
+        match core::str::from_utf8(&self.bytes[..take]) {
+            Ok(valid) => {
+                let c = valid.chars().next().expect("non-empty");
+                self.bytes = &self.bytes[c.len_utf8()..];
+                Some(c)
+            }
+            Err(e) => {
+                // `error_len` is the length of the maximal ill-formed
+                // subsequence per the Unicode substitution rules; a `None`
+                // length means the window is a truncated trailing sequence.
+                let skip = e.error_len().unwrap_or(take).max(1);
+                self.bytes = &self.bytes[skip..];
+                Some(REPLACEMENT_CHARACTER)
+            }
+        }
+    }
+}
+
+/// Number of bytes a well-formed UTF-8 scalar beginning with `b` would
+/// occupy, or `0` if `b` cannot begin a well-formed sequence.
+#[inline]
+fn utf8_width(b: u8) -> usize {
+    match b {
+        0x00..=0x7F => 1,
+        0xC2..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF4 => 4,
+        _ => 0,
+    }
+}
+
+/// Iterator over the scalar values of a potentially-invalid UTF-16 slice,
+/// emitting `U+FFFD` for each unpaired surrogate.
+///
+/// Created with [`from_utf16`].
+#[derive(Clone, Debug)]
+pub struct Utf16Chars<'a> {
+    units: &'a [u16],
+}
+
+impl<'a> Iterator for Utf16Chars<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let (&u, rest) = self.units.split_first()?;
+        self.units = rest;
+        if !is_surrogate(u) {
+            return Some(char_from_u32(u32::from(u)));
+        }
+        if is_high_surrogate(u) {
+            if let Some((&low, rest)) = self.units.split_first() {
+                if is_low_surrogate(low) {
+                    self.units = rest;
+                    let c = 0x1_0000
+                        + ((u32::from(u) - 0xD800) << 10)
+                        + (u32::from(low) - 0xDC00);
+                    return Some(char_from_u32(c));
+                }
+            }
+        }
+        // An unpaired low surrogate, or a high surrogate not followed by a
+        // low one, is a maximal ill-formed subsequence of one code unit.
+        Some(REPLACEMENT_CHARACTER)
+    }
+}
+
+#[inline]
+fn is_surrogate(u: u16) -> bool {
+    (0xD800..=0xDFFF).contains(&u)
+}
+
+#[inline]
+fn is_high_surrogate(u: u16) -> bool {
+    (0xD800..=0xDBFF).contains(&u)
+}
+
+#[inline]
+fn is_low_surrogate(u: u16) -> bool {
+    (0xDC00..=0xDFFF).contains(&u)
+}
+
+#[inline]
+fn char_from_u32(c: u32) -> char {
+    char::from_u32(c).unwrap_or(REPLACEMENT_CHARACTER)
+}